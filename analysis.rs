@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::{evaluate_steps, ordered_variables, AST};
+
+/// Where a formula lands once every row of its truth table has been
+/// enumerated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    /// True in every row.
+    Tautology,
+    /// False in every row.
+    Contradiction,
+    /// True in at least one row (and false in at least one other).
+    Contingent,
+}
+
+/// Result of a full pass over a formula's truth table: its
+/// tautology/contradiction/contingent classification, a satisfying
+/// assignment when one exists, and the canonical DNF/CNF derived
+/// directly from the rows.
+pub struct Analysis {
+    pub classification: Classification,
+    pub satisfying_assignment: Option<Vec<(String, bool)>>,
+    pub dnf: String,
+    pub cnf: String,
+}
+
+/// Enumerates every row of `ast`'s truth table (in the same variable
+/// order `generate_truth_table` uses) and derives a classification plus
+/// canonical normal forms from it.
+pub fn analyze(ast: &AST) -> Analysis {
+    let vars = ordered_variables(ast);
+    let total_rows = 1u32 << vars.len();
+
+    let mut satisfying_assignment = None;
+    let mut dnf_terms = Vec::new();
+    let mut cnf_terms = Vec::new();
+    let mut any_true = false;
+    let mut any_false = false;
+
+    for i in 0..total_rows {
+        let mut values = HashMap::new();
+        for (j, var) in vars.iter().enumerate() {
+            values.insert(var.clone(), (i & (1 << j)) != 0);
+        }
+        let mut steps = HashMap::new();
+        let result = evaluate_steps(ast, &values, &mut steps);
+
+        if result {
+            any_true = true;
+            if satisfying_assignment.is_none() {
+                let assignment = vars.iter().map(|v| (v.clone(), values[v])).collect();
+                satisfying_assignment = Some(assignment);
+            }
+            dnf_terms.push(minterm(&vars, &values));
+        } else {
+            any_false = true;
+            cnf_terms.push(maxterm(&vars, &values));
+        }
+    }
+
+    let classification = match (any_true, any_false) {
+        (true, false) => Classification::Tautology,
+        (false, true) => Classification::Contradiction,
+        _ => Classification::Contingent,
+    };
+
+    let dnf = if dnf_terms.is_empty() {
+        "0".to_string()
+    } else {
+        dnf_terms.join(" or ")
+    };
+    let cnf = if cnf_terms.is_empty() {
+        "1".to_string()
+    } else {
+        cnf_terms.join(" and ")
+    };
+
+    Analysis {
+        classification,
+        satisfying_assignment,
+        dnf,
+        cnf,
+    }
+}
+
+/// The AND of each variable (or its negation) matching a true row.
+fn minterm(vars: &[String], values: &HashMap<String, bool>) -> String {
+    let literals: Vec<String> = vars
+        .iter()
+        .map(|v| if values[v] { v.clone() } else { format!("not {v}") })
+        .collect();
+    if literals.len() == 1 {
+        literals.into_iter().next().unwrap()
+    } else {
+        format!("( {} )", literals.join(" and "))
+    }
+}
+
+/// The OR of each variable (or its negation) matching a false row.
+fn maxterm(vars: &[String], values: &HashMap<String, bool>) -> String {
+    let literals: Vec<String> = vars
+        .iter()
+        .map(|v| if values[v] { format!("not {v}") } else { v.clone() })
+        .collect();
+    if literals.len() == 1 {
+        literals.into_iter().next().unwrap()
+    } else {
+        format!("( {} )", literals.join(" or "))
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::error::CedilhaError;
+use crate::{parse_expr, tokenize, AST};
+
+/// Keeps named sub-formulas bound via `let name = expression`, so later
+/// expressions can reference them by name instead of repeating them.
+#[derive(Debug, Default)]
+pub struct Environment {
+    definitions: std::collections::HashMap<String, AST>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads one `let name = expression` definition per non-empty line.
+    pub fn load_from_str(&mut self, contents: &str) -> Result<(), CedilhaError> {
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            self.define_line(line)?;
+        }
+        Ok(())
+    }
+
+    /// Parses a single `let name = expression` line and stores the
+    /// resulting AST under `name`, shadowing any previous definition.
+    pub fn define_line(&mut self, line: &str) -> Result<(), CedilhaError> {
+        let rest = line
+            .strip_prefix("let ")
+            .ok_or_else(|| CedilhaError::MalformedDefinition(line.to_string()))?;
+        let (name, expr_src) = rest
+            .split_once('=')
+            .ok_or_else(|| CedilhaError::MalformedDefinition(line.to_string()))?;
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(CedilhaError::MalformedDefinition(line.to_string()));
+        }
+
+        let tokens = tokenize(expr_src.trim());
+        let mut queue = VecDeque::from(tokens);
+        let ast = parse_expr(&mut queue)?;
+        self.definitions.insert(name, ast);
+        Ok(())
+    }
+
+    /// Expands every bare identifier in `ast` that matches a defined
+    /// name into its stored AST, leaving unknown identifiers (the actual
+    /// leaf variables) untouched. Rejects definitions that reference
+    /// themselves, directly or through another name, as a cycle.
+    pub fn expand_expr(&self, ast: &AST) -> Result<AST, CedilhaError> {
+        self.expand(ast, &mut HashSet::new())
+    }
+
+    fn expand(&self, ast: &AST, visiting: &mut HashSet<String>) -> Result<AST, CedilhaError> {
+        match ast {
+            AST::Var(name) => match self.definitions.get(name) {
+                Some(def) => {
+                    if !visiting.insert(name.clone()) {
+                        return Err(CedilhaError::CyclicDefinition(name.clone()));
+                    }
+                    let expanded = self.expand(def, visiting)?;
+                    visiting.remove(name);
+                    Ok(expanded)
+                }
+                None => Ok(AST::Var(name.clone())),
+            },
+            AST::Not(expr) => Ok(AST::Not(Box::new(self.expand(expr, visiting)?))),
+            AST::And(left, right) => self.expand_binary(left, right, visiting, AST::And),
+            AST::Or(left, right) => self.expand_binary(left, right, visiting, AST::Or),
+            AST::Xor(left, right) => self.expand_binary(left, right, visiting, AST::Xor),
+            AST::Implies(left, right) => self.expand_binary(left, right, visiting, AST::Implies),
+            AST::Iff(left, right) => self.expand_binary(left, right, visiting, AST::Iff),
+            AST::Nand(left, right) => self.expand_binary(left, right, visiting, AST::Nand),
+            AST::Nor(left, right) => self.expand_binary(left, right, visiting, AST::Nor),
+        }
+    }
+
+    fn expand_binary(
+        &self,
+        left: &AST,
+        right: &AST,
+        visiting: &mut HashSet<String>,
+        make: impl FnOnce(Box<AST>, Box<AST>) -> AST,
+    ) -> Result<AST, CedilhaError> {
+        let left = self.expand(left, visiting)?;
+        let right = self.expand(right, visiting)?;
+        Ok(make(Box::new(left), Box::new(right)))
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use crate::Token;
+
+/// Errors produced while parsing a boolean expression or while binding
+/// and expanding named definitions.
+///
+/// `UnbalancedParenthesis` and `UnexpectedToken` carry the word offset
+/// (`pos`) of the offending token in the input, so callers can point the
+/// user at the exact spot that went wrong. `DanglingOperator` carries the
+/// operator token only; it is always the last token of the input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CedilhaError {
+    /// A `(` was never closed before the input ran out. `pos` is the
+    /// offset of the offending `(`.
+    UnbalancedParenthesis { pos: usize },
+    /// The input had no tokens at all.
+    EmptyExpression,
+    /// An operator (`and`, `or`, `not`, `xor`, `nand`, `nor`, `->` or
+    /// `<->`) was the last token in the input, with nothing left for it
+    /// to operate on.
+    DanglingOperator(Token),
+    /// An operand was expected but the input ran out before one was found.
+    MissingOperand,
+    /// A token showed up somewhere the parser wasn't expecting it, such
+    /// as a stray `)` with no matching `(`.
+    UnexpectedToken { token: Token, pos: usize },
+    /// A `let` binding was missing its `name = expression` shape.
+    MalformedDefinition(String),
+    /// Expanding a named definition looped back on itself.
+    CyclicDefinition(String),
+}
+
+impl fmt::Display for CedilhaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CedilhaError::UnbalancedParenthesis { pos } => {
+                write!(f, "parêntese não fechado a partir da posição {pos}")
+            }
+            CedilhaError::EmptyExpression => write!(f, "expressão vazia"),
+            CedilhaError::DanglingOperator(token) => {
+                write!(f, "operador {token:?} no fim da expressão, sem operando")
+            }
+            CedilhaError::MissingOperand => {
+                write!(f, "operador sem operando suficiente para ser avaliado")
+            }
+            CedilhaError::UnexpectedToken { token, pos } => {
+                write!(f, "token inesperado {token:?} na posição {pos}")
+            }
+            CedilhaError::MalformedDefinition(line) => {
+                write!(f, "definição malformada, esperado 'let nome = expressão': {line}")
+            }
+            CedilhaError::CyclicDefinition(name) => {
+                write!(f, "definição recursiva envolvendo '{name}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CedilhaError {}
@@ -1,73 +1,253 @@
 use std::collections::{VecDeque, HashMap, HashSet};
+use clap::{Parser};
+use serde::{Deserialize, Serialize};
+
+mod analysis;
+mod env;
+mod error;
+mod minimize;
+mod output;
+mod repl;
+use analysis::Classification;
+use error::CedilhaError;
+
+#[derive(Parser, Debug)]
+#[command(name = "BooleanLang")]
+struct Args {
+    #[arg(long = "exp", help = "Expressão booleana a ser avaliada")]
+    expression: Option<String>,
+
+    #[arg(short = 's', long = "silence", help = "Modo silencioso, sem imprimir a tabela verdade")]
+    s: bool,
+
+    #[arg(short = 'e', long = "expand", help = "Expande os cálculos intermediários")]
+    e: bool,
+
+    #[arg(long = "repl", help = "Força o modo interativo mesmo com --exp definido")]
+    repl: bool,
+
+    #[arg(long = "classify", help = "Classifica a expressão em tautologia, contradição ou contingente")]
+    classify: bool,
+
+    #[arg(long = "normal-form", value_enum, help = "Exibe a forma normal canônica da expressão")]
+    normal_form: Option<NormalForm>,
+
+    #[arg(long = "minimize", help = "Minimiza a expressão via Quine–McCluskey")]
+    minimize: bool,
+
+    #[arg(long = "defs", help = "Arquivo com definições 'let nome = expressão', uma por linha")]
+    defs: Option<String>,
+
+    #[arg(long = "output", value_enum, default_value = "text", help = "Formato da tabela verdade: json, csv, markdown ou text")]
+    output: OutputFormat,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    Csv,
+    Markdown,
+    Text,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+enum NormalForm {
+    Dnf,
+    Cnf,
+}
 
 #[derive(Debug, Clone, PartialEq)]
 enum Token {
     And,
     Or,
     Not,
+    Xor,
+    Implies,
+    Iff,
+    Nand,
+    Nor,
     LParen,
     RParen,
     Var(String),
 }
 
-fn tokenize(input: &str) -> Vec<Token> {
+/// A token together with its word offset in the original input, so a
+/// `CedilhaError` can point back at where it came from.
+#[derive(Debug, Clone, PartialEq)]
+struct PositionedToken {
+    token: Token,
+    pos: usize,
+}
+
+fn tokenize(input: &str) -> Vec<PositionedToken> {
     let mut tokens = Vec::new();
     let words: Vec<&str> = input.split_whitespace().collect();
-    
-    for word in words {
-        match word {
-            "and" => tokens.push(Token::And),
-            "or" => tokens.push(Token::Or),
-            "not" => tokens.push(Token::Not),
-            "(" => tokens.push(Token::LParen),
-            ")" => tokens.push(Token::RParen),
-            var => tokens.push(Token::Var(var.to_string())),
-        }
-    }
-    
+
+    for (pos, word) in words.into_iter().enumerate() {
+        let token = match word {
+            "and" => Token::And,
+            "or" => Token::Or,
+            "not" => Token::Not,
+            "xor" => Token::Xor,
+            "nand" => Token::Nand,
+            "nor" => Token::Nor,
+            "->" => Token::Implies,
+            "<->" => Token::Iff,
+            "(" => Token::LParen,
+            ")" => Token::RParen,
+            var => Token::Var(var.to_string()),
+        };
+        tokens.push(PositionedToken { token, pos });
+    }
+
     tokens
 }
 
-#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Serialize, Deserialize)]
 enum AST {
     And(Box<AST>, Box<AST>),
     Or(Box<AST>, Box<AST>),
     Not(Box<AST>),
+    Xor(Box<AST>, Box<AST>),
+    Implies(Box<AST>, Box<AST>),
+    Iff(Box<AST>, Box<AST>),
+    Nand(Box<AST>, Box<AST>),
+    Nor(Box<AST>, Box<AST>),
     Var(String),
 }
 
-fn parse(tokens: &mut VecDeque<Token>) -> Option<AST> {
-    let mut stack: Vec<AST> = Vec::new();
-    
-    while let Some(token) = tokens.pop_front() {
-        match token {
-            Token::Var(v) => stack.push(AST::Var(v)),
-            Token::Not => {
-                if let Some(expr) = stack.pop() {
-                    stack.push(AST::Not(Box::new(expr)));
+/// Recursive-descent parser over the token stream, tightest to loosest:
+/// `not` > `and`/`nand` > `or`/`nor`/`xor` > `->` > `<->`, with `->`
+/// right-associative and the rest left-associative.
+fn parse_expr(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    if tokens.is_empty() {
+        return Err(CedilhaError::EmptyExpression);
+    }
+    if let Some(PositionedToken { token, .. }) = tokens.back()
+        && is_operator(token)
+    {
+        return Err(CedilhaError::DanglingOperator(token.clone()));
+    }
+
+    let ast = parse_iff(tokens)?;
+    if let Some(PositionedToken { token, pos }) = tokens.pop_front() {
+        return Err(CedilhaError::UnexpectedToken { token, pos });
+    }
+    Ok(ast)
+}
+
+fn is_operator(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::And
+            | Token::Or
+            | Token::Not
+            | Token::Xor
+            | Token::Implies
+            | Token::Iff
+            | Token::Nand
+            | Token::Nor
+    )
+}
+
+fn parse_iff(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    let mut left = parse_implies(tokens)?;
+    while matches!(tokens.front(), Some(PositionedToken { token: Token::Iff, .. })) {
+        tokens.pop_front();
+        let right = parse_implies(tokens)?;
+        left = AST::Iff(Box::new(left), Box::new(right));
+    }
+    Ok(left)
+}
+
+fn parse_implies(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    let left = parse_or(tokens)?;
+    if matches!(tokens.front(), Some(PositionedToken { token: Token::Implies, .. })) {
+        tokens.pop_front();
+        let right = parse_implies(tokens)?; // right-associative
+        return Ok(AST::Implies(Box::new(left), Box::new(right)));
+    }
+    Ok(left)
+}
+
+fn parse_or(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    let mut left = parse_and(tokens)?;
+    loop {
+        let op = match tokens.front().map(|pt| &pt.token) {
+            Some(Token::Or) => Token::Or,
+            Some(Token::Nor) => Token::Nor,
+            Some(Token::Xor) => Token::Xor,
+            _ => break,
+        };
+        tokens.pop_front();
+        let right = parse_and(tokens)?;
+        left = match op {
+            Token::Or => AST::Or(Box::new(left), Box::new(right)),
+            Token::Nor => AST::Nor(Box::new(left), Box::new(right)),
+            Token::Xor => AST::Xor(Box::new(left), Box::new(right)),
+            _ => unreachable!(),
+        };
+    }
+    Ok(left)
+}
+
+fn parse_and(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    let mut left = parse_not(tokens)?;
+    loop {
+        let op = match tokens.front().map(|pt| &pt.token) {
+            Some(Token::And) => Token::And,
+            Some(Token::Nand) => Token::Nand,
+            _ => break,
+        };
+        tokens.pop_front();
+        let right = parse_not(tokens)?;
+        left = match op {
+            Token::And => AST::And(Box::new(left), Box::new(right)),
+            Token::Nand => AST::Nand(Box::new(left), Box::new(right)),
+            _ => unreachable!(),
+        };
+    }
+    Ok(left)
+}
+
+fn parse_not(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    if matches!(tokens.front(), Some(PositionedToken { token: Token::Not, .. })) {
+        tokens.pop_front();
+        let operand = parse_not(tokens)?;
+        return Ok(AST::Not(Box::new(operand)));
+    }
+    parse_atom(tokens)
+}
+
+fn parse_atom(tokens: &mut VecDeque<PositionedToken>) -> Result<AST, CedilhaError> {
+    let PositionedToken { token, pos } = tokens.pop_front().ok_or(CedilhaError::MissingOperand)?;
+    match token {
+        Token::Var(v) => Ok(AST::Var(v)),
+        Token::LParen => {
+            let inner = parse_iff(tokens)?;
+            match tokens.pop_front() {
+                Some(PositionedToken { token: Token::RParen, .. }) => Ok(inner),
+                Some(PositionedToken { token, pos }) => {
+                    Err(CedilhaError::UnexpectedToken { token, pos })
                 }
+                None => Err(CedilhaError::UnbalancedParenthesis { pos }),
             }
-            Token::And | Token::Or => {
-                let right = stack.pop()?;
-                let left = stack.pop()?;
-                let node = match token {
-                    Token::And => AST::And(Box::new(left), Box::new(right)),
-                    Token::Or => AST::Or(Box::new(left), Box::new(right)),
-                    _ => unreachable!(),
-                };
-                stack.push(node);
-            }
-            _ => {}
         }
+        other => Err(CedilhaError::UnexpectedToken { token: other, pos }),
     }
-    
-    stack.pop()
 }
 
 fn extract_variables(ast: &AST, vars: &mut HashSet<String>) {
     match ast {
         AST::Var(v) => { vars.insert(v.clone()); },
-        AST::And(left, right) | AST::Or(left, right) => {
+        AST::And(left, right)
+        | AST::Or(left, right)
+        | AST::Xor(left, right)
+        | AST::Implies(left, right)
+        | AST::Iff(left, right)
+        | AST::Nand(left, right)
+        | AST::Nor(left, right) => {
             extract_variables(left, vars);
             extract_variables(right, vars);
         }
@@ -98,43 +278,229 @@ fn evaluate_steps(ast: &AST, values: &HashMap<String, bool>, steps: &mut HashMap
             steps.insert(format!("not {:?}", expr), result);
             result
         }
+        AST::Xor(left, right) => {
+            let l = evaluate_steps(left, values, steps);
+            let r = evaluate_steps(right, values, steps);
+            let result = l != r;
+            steps.insert(format!("({:?} xor {:?})", left, right), result);
+            result
+        }
+        AST::Implies(left, right) => {
+            let l = evaluate_steps(left, values, steps);
+            let r = evaluate_steps(right, values, steps);
+            let result = !l || r;
+            steps.insert(format!("({:?} -> {:?})", left, right), result);
+            result
+        }
+        AST::Iff(left, right) => {
+            let l = evaluate_steps(left, values, steps);
+            let r = evaluate_steps(right, values, steps);
+            let result = l == r;
+            steps.insert(format!("({:?} <-> {:?})", left, right), result);
+            result
+        }
+        AST::Nand(left, right) => {
+            let l = evaluate_steps(left, values, steps);
+            let r = evaluate_steps(right, values, steps);
+            let result = !(l && r);
+            steps.insert(format!("({:?} nand {:?})", left, right), result);
+            result
+        }
+        AST::Nor(left, right) => {
+            let l = evaluate_steps(left, values, steps);
+            let r = evaluate_steps(right, values, steps);
+            let result = !(l || r);
+            steps.insert(format!("({:?} nor {:?})", left, right), result);
+            result
+        }
     }
 }
 
-fn generate_truth_table(ast: &AST) {
+/// Variables in `ast`, deduplicated and sorted so every caller (truth
+/// table, classification, normal forms) agrees on the same column order.
+fn ordered_variables(ast: &AST) -> Vec<String> {
     let mut vars = HashSet::new();
     extract_variables(ast, &mut vars);
-    let vars: Vec<String> = vars.into_iter().collect();
-    let total_rows = 1 << vars.len();
-    
-    println!("\nTabela Verdade:");
-    println!("{:?} => Result", vars);
-    
-    for i in 0..total_rows {
-        let mut values = HashMap::new();
-        let mut steps = HashMap::new();
-        
-        for (j, var) in vars.iter().enumerate() {
-            values.insert(var.clone(), (i & (1 << j)) != 0);
-        }
-        let result = evaluate_steps(ast, &values, &mut steps);
-        
-        println!("{:?} => {}", values, result);
-        
-        println!("Passos do cálculo:");
-        for (step, res) in &steps {
-            println!("{} = {}", step, res);
-        }
-        println!("-------------------");
+    let mut vars: Vec<String> = vars.into_iter().collect();
+    vars.sort();
+    vars
+}
+
+fn generate_truth_table(ast: &AST) {
+    let vars = ordered_variables(ast);
+    let table = output::build_truth_table(ast, &vars, true);
+    output::print_text(&table);
+}
+
+fn load_environment(args: &Args) -> env::Environment {
+    let mut environment = env::Environment::new();
+    if let Some(path) = &args.defs {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => {
+                if let Err(err) = environment.load_from_str(&contents) {
+                    eprintln!("Erro ao carregar definições de \"{path}\": {err}");
+                    std::process::exit(1);
+                }
+            }
+            Err(err) => {
+                eprintln!("Erro ao ler arquivo de definições \"{path}\": {err}");
+                std::process::exit(1);
+            }
+        }
     }
+    environment
 }
 
 fn main() {
-    let input = "a and (b or not c)";
-    let tokens = tokenize(input);
+    let args = Args::parse();
+    let environment = load_environment(&args);
+
+    if args.repl || args.expression.is_none() {
+        if let Err(err) = repl::run(environment) {
+            eprintln!("Erro no modo interativo: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let input = args.expression.clone().unwrap();
+    let tokens = tokenize(&input);
     let mut token_queue = VecDeque::from(tokens);
-    if let Some(ast) = parse(&mut token_queue) {
-        println!("AST: {:?}", ast);
-        generate_truth_table(&ast);
+
+    match parse_expr(&mut token_queue).and_then(|ast| environment.expand_expr(&ast)) {
+        Ok(ast) => {
+            println!("AST: {:?}", ast);
+            if !args.s {
+                let vars = ordered_variables(&ast);
+                let table = output::build_truth_table(&ast, &vars, args.e);
+                match args.output {
+                    OutputFormat::Text => output::print_text(&table),
+                    OutputFormat::Json => output::print_json(&ast, &table),
+                    OutputFormat::Csv => output::print_csv(&table),
+                    OutputFormat::Markdown => output::print_markdown(&table),
+                }
+            }
+            if args.classify || args.normal_form.is_some() {
+                print_analysis(&ast, &args);
+            }
+            if args.minimize {
+                let vars = ordered_variables(&ast);
+                let minterms = minimize::collect_minterms(&ast, &vars);
+                let terms = minimize::minimize(&minterms, &[], vars.len());
+                println!("Minimizado: {}", minimize::render(&terms, &vars));
+            }
+        }
+        Err(err) => {
+            eprintln!("Erro ao interpretar \"{input}\": {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn print_analysis(ast: &AST, args: &Args) {
+    let analysis = analysis::analyze(ast);
+
+    if args.classify {
+        match analysis.classification {
+            Classification::Tautology => println!("Classificação: tautologia"),
+            Classification::Contradiction => println!("Classificação: contradição"),
+            Classification::Contingent => println!("Classificação: contingente (satisfazível)"),
+        }
+        match &analysis.satisfying_assignment {
+            Some(assignment) => println!("Atribuição satisfatória: {:?}", assignment),
+            None => println!("Nenhuma atribuição satisfatória"),
+        }
+    }
+
+    match args.normal_form {
+        Some(NormalForm::Dnf) => println!("DNF: {}", analysis.dnf),
+        Some(NormalForm::Cnf) => println!("CNF: {}", analysis.cnf),
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<AST, CedilhaError> {
+        let tokens = tokenize(input);
+        let mut queue = VecDeque::from(tokens);
+        parse_expr(&mut queue)
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let ast = parse("a or b and c").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"Or(Var("a"), And(Var("b"), Var("c")))"#
+        );
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and() {
+        let ast = parse("not a and b or c").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"Or(And(Not(Var("a")), Var("b")), Var("c"))"#
+        );
+    }
+
+    #[test]
+    fn implies_is_right_associative() {
+        let ast = parse("a -> b -> c").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"Implies(Var("a"), Implies(Var("b"), Var("c")))"#
+        );
+    }
+
+    #[test]
+    fn and_is_left_associative() {
+        let ast = parse("a and b and c").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"And(And(Var("a"), Var("b")), Var("c"))"#
+        );
+    }
+
+    #[test]
+    fn implies_binds_looser_than_or_and_xor() {
+        let ast = parse("a or b -> c xor d").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"Implies(Or(Var("a"), Var("b")), Xor(Var("c"), Var("d")))"#
+        );
+    }
+
+    #[test]
+    fn iff_is_left_associative() {
+        let ast = parse("a <-> b <-> c").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"Iff(Iff(Var("a"), Var("b")), Var("c"))"#
+        );
+    }
+
+    #[test]
+    fn parens_override_precedence() {
+        let ast = parse("( a or b ) and c").unwrap();
+        assert_eq!(
+            format!("{ast:?}"),
+            r#"And(Or(Var("a"), Var("b")), Var("c"))"#
+        );
+    }
+
+    #[test]
+    fn dangling_operator_is_rejected() {
+        let err = parse("a and").unwrap_err();
+        assert_eq!(err, CedilhaError::DanglingOperator(Token::And));
+    }
+
+    #[test]
+    fn empty_expression_is_rejected() {
+        let err = parse("").unwrap_err();
+        assert_eq!(err, CedilhaError::EmptyExpression);
     }
 }
@@ -0,0 +1,267 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::AST;
+
+/// A Quine–McCluskey term over `n` variables: `Some(b)` pins a variable
+/// to `b`, `None` is a don't-care dash produced by combining two terms
+/// that differed in exactly that bit.
+pub type Term = Vec<Option<bool>>;
+
+/// The minterms (integer row indices where `ast` evaluates true) over
+/// `vars`, in the same order `generate_truth_table` enumerates rows.
+pub fn collect_minterms(ast: &AST, vars: &[String]) -> Vec<u32> {
+    let total_rows = 1u32 << vars.len();
+    let mut minterms = Vec::new();
+
+    for i in 0..total_rows {
+        let mut values = HashMap::new();
+        for (j, var) in vars.iter().enumerate() {
+            values.insert(var.clone(), (i & (1 << j)) != 0);
+        }
+        let mut steps = HashMap::new();
+        if crate::evaluate_steps(ast, &values, &mut steps) {
+            minterms.push(i);
+        }
+    }
+
+    minterms
+}
+
+fn term_from_minterm(m: u32, num_vars: usize) -> Term {
+    (0..num_vars).map(|j| Some((m >> j) & 1 == 1)).collect()
+}
+
+fn popcount(term: &Term) -> usize {
+    term.iter().filter(|b| **b == Some(true)).count()
+}
+
+/// Combines two terms into one with the differing bit replaced by a
+/// don't-care, or `None` if they don't differ in exactly one bit.
+fn combine(a: &Term, b: &Term) -> Option<Term> {
+    let mut diff_at = None;
+    for i in 0..a.len() {
+        if a[i] != b[i] {
+            if diff_at.is_some() {
+                return None;
+            }
+            diff_at = Some(i);
+        }
+    }
+    let i = diff_at?;
+    let mut combined = a.clone();
+    combined[i] = None;
+    Some(combined)
+}
+
+fn covers(term: &Term, minterm: u32) -> bool {
+    term.iter()
+        .enumerate()
+        .all(|(j, bit)| match bit {
+            Some(b) => *b == ((minterm >> j) & 1 == 1),
+            None => true,
+        })
+}
+
+/// Runs Quine–McCluskey over `minterms` (the rows that must be covered)
+/// and `dont_cares` (rows that may be folded in while combining but need
+/// not be covered by the result), returning the prime implicants chosen
+/// for a minimal sum-of-products cover.
+///
+/// An all-true table (`minterms` spans every row) minimizes to the
+/// constant tautology, represented as a single all-dash term; an
+/// all-false table minimizes to the empty term list (constant `0`).
+pub fn minimize(minterms: &[u32], dont_cares: &[u32], num_vars: usize) -> Vec<Term> {
+    if minterms.is_empty() {
+        return Vec::new();
+    }
+    let total_rows = if num_vars == 0 { 1 } else { 1usize << num_vars };
+    if minterms.len() == total_rows {
+        return vec![vec![None; num_vars]];
+    }
+
+    let mut all: Vec<Term> = minterms
+        .iter()
+        .chain(dont_cares.iter())
+        .map(|&m| term_from_minterm(m, num_vars))
+        .collect();
+    all.sort();
+    all.dedup();
+
+    let mut primes: Vec<Term> = Vec::new();
+    let mut current = all;
+
+    loop {
+        let mut groups: HashMap<usize, Vec<Term>> = HashMap::new();
+        for t in &current {
+            groups.entry(popcount(t)).or_default().push(t.clone());
+        }
+
+        let mut used: HashSet<Term> = HashSet::new();
+        let mut next: Vec<Term> = Vec::new();
+        let mut keys: Vec<usize> = groups.keys().copied().collect();
+        keys.sort_unstable();
+
+        for k in &keys {
+            let (Some(lower), Some(upper)) = (groups.get(k), groups.get(&(k + 1))) else {
+                continue;
+            };
+            for a in lower {
+                for b in upper {
+                    if let Some(combined) = combine(a, b) {
+                        used.insert(a.clone());
+                        used.insert(b.clone());
+                        if !next.contains(&combined) {
+                            next.push(combined);
+                        }
+                    }
+                }
+            }
+        }
+
+        for t in &current {
+            if !used.contains(t) && !primes.contains(t) {
+                primes.push(t.clone());
+            }
+        }
+
+        if next.is_empty() {
+            break;
+        }
+        current = next;
+    }
+
+    cover(&primes, minterms)
+}
+
+/// Builds the prime-implicant chart, takes essential prime implicants
+/// first, then greedily covers whatever minterms remain.
+fn cover(primes: &[Term], minterms: &[u32]) -> Vec<Term> {
+    let mut chart: HashMap<u32, Vec<usize>> = HashMap::new();
+    for &m in minterms {
+        for (idx, p) in primes.iter().enumerate() {
+            if covers(p, m) {
+                chart.entry(m).or_default().push(idx);
+            }
+        }
+    }
+
+    let mut chosen: HashSet<usize> = HashSet::new();
+    for implicants in chart.values() {
+        if let [only] = implicants[..] {
+            chosen.insert(only);
+        }
+    }
+
+    let mut covered: HashSet<u32> = minterms
+        .iter()
+        .copied()
+        .filter(|m| chart[m].iter().any(|idx| chosen.contains(idx)))
+        .collect();
+
+    loop {
+        let remaining: Vec<u32> = minterms
+            .iter()
+            .copied()
+            .filter(|m| !covered.contains(m))
+            .collect();
+        if remaining.is_empty() {
+            break;
+        }
+
+        let best = primes
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !chosen.contains(idx))
+            .max_by_key(|(_, p)| remaining.iter().filter(|&&m| covers(p, m)).count());
+
+        match best {
+            Some((idx, p)) => {
+                chosen.insert(idx);
+                covered.extend(remaining.into_iter().filter(|&m| covers(p, m)));
+            }
+            None => break,
+        }
+    }
+
+    let mut result: Vec<Term> = chosen.into_iter().map(|idx| primes[idx].clone()).collect();
+    result.sort();
+    result
+}
+
+/// Renders the chosen prime implicants back into an `and`/`or`/`not`
+/// expression over `vars`, in the same column order they were derived.
+pub fn render(terms: &[Term], vars: &[String]) -> String {
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    let products: Vec<String> = terms.iter().map(|t| render_term(t, vars)).collect();
+    if products.len() == 1 {
+        products.into_iter().next().unwrap()
+    } else {
+        products.join(" or ")
+    }
+}
+
+fn render_term(term: &Term, vars: &[String]) -> String {
+    let literals: Vec<String> = term
+        .iter()
+        .zip(vars.iter())
+        .filter_map(|(bit, name)| match bit {
+            Some(true) => Some(name.clone()),
+            Some(false) => Some(format!("not {name}")),
+            None => None,
+        })
+        .collect();
+
+    if literals.is_empty() {
+        "1".to_string()
+    } else if literals.len() == 1 {
+        literals.into_iter().next().unwrap()
+    } else {
+        format!("( {} )", literals.join(" and "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    fn parse(input: &str) -> crate::AST {
+        let tokens = crate::tokenize(input);
+        let mut queue = VecDeque::from(tokens);
+        crate::parse_expr(&mut queue).expect("valid expression")
+    }
+
+    fn minimize_expr(input: &str) -> String {
+        let ast = parse(input);
+        let vars = crate::ordered_variables(&ast);
+        let minterms = collect_minterms(&ast, &vars);
+        let terms = minimize(&minterms, &[], vars.len());
+        render(&terms, &vars)
+    }
+
+    #[test]
+    fn absorbs_redundant_literal() {
+        assert_eq!(minimize_expr("a and b or a and not b"), "a");
+    }
+
+    #[test]
+    fn all_true_minimizes_to_constant_one() {
+        assert_eq!(minimize_expr("a or not a"), "1");
+    }
+
+    #[test]
+    fn all_false_minimizes_to_constant_zero() {
+        assert_eq!(minimize_expr("a and not a"), "0");
+    }
+
+    #[test]
+    fn rendered_expression_round_trips_through_the_parser() {
+        let rendered =
+            minimize_expr("( a and b and c ) or ( a and b and not c ) or ( a and not b and not c )");
+        // The rendered output must be valid input to this tool's own
+        // grammar, not just human-readable text.
+        parse(&rendered);
+    }
+}
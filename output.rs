@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::AST;
+
+/// One row of a truth table: the variable assignment that produced it,
+/// the final result, and (when requested) every intermediate sub-result
+/// `evaluate_steps` computed along the way.
+#[derive(Debug, Serialize)]
+pub struct TruthTableRow {
+    pub assignment: Vec<(String, bool)>,
+    pub result: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<Vec<(String, bool)>>,
+}
+
+/// A fully enumerated truth table: the variables in column order, plus
+/// one row per assignment. Built once by `build_truth_table` and then
+/// rendered by any of the `print_*` functions below, so the evaluator
+/// itself stays observable without going through stdout.
+#[derive(Debug, Serialize)]
+pub struct TruthTable {
+    pub variables: Vec<String>,
+    pub rows: Vec<TruthTableRow>,
+}
+
+/// Enumerates every row of `ast`'s truth table over `vars`. When
+/// `include_steps` is set, each row also carries the intermediate
+/// sub-results `evaluate_steps` produced for it.
+pub fn build_truth_table(ast: &AST, vars: &[String], include_steps: bool) -> TruthTable {
+    let total_rows = 1 << vars.len();
+    let mut rows = Vec::with_capacity(total_rows);
+
+    for i in 0..total_rows {
+        let mut values = HashMap::new();
+        for (j, var) in vars.iter().enumerate() {
+            values.insert(var.clone(), (i & (1 << j)) != 0);
+        }
+
+        let mut steps = HashMap::new();
+        let result = crate::evaluate_steps(ast, &values, &mut steps);
+        let assignment = vars.iter().map(|v| (v.clone(), values[v])).collect();
+        let steps = include_steps.then(|| steps.into_iter().collect());
+
+        rows.push(TruthTableRow {
+            assignment,
+            result,
+            steps,
+        });
+    }
+
+    TruthTable {
+        variables: vars.to_vec(),
+        rows,
+    }
+}
+
+/// Renders `table` the way `generate_truth_table` always has: a header
+/// line, one `{var: value, ...} => result` line per row, and (when
+/// present) the intermediate steps underneath it.
+pub fn print_text(table: &TruthTable) {
+    println!("\nTabela Verdade:");
+    println!("{:?} => Resultado", table.variables);
+
+    for row in &table.rows {
+        let values: Vec<String> = row
+            .assignment
+            .iter()
+            .map(|(var, value)| format!("{var:?}: {value}"))
+            .collect();
+        println!("{{{}}} => {}", values.join(", "), row.result);
+        if let Some(steps) = &row.steps {
+            println!("Passos do cálculo:");
+            for (step, res) in steps {
+                println!("{step} = {res}");
+            }
+        }
+        println!("-------------------");
+    }
+}
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    ast: &'a AST,
+    table: &'a TruthTable,
+}
+
+/// Dumps the AST and the full table as JSON, for tooling/pipelines.
+pub fn print_json(ast: &AST, table: &TruthTable) {
+    match render_json(ast, table) {
+        Ok(json) => println!("{json}"),
+        Err(err) => eprintln!("Erro ao serializar para JSON: {err}"),
+    }
+}
+
+fn render_json(ast: &AST, table: &TruthTable) -> serde_json::Result<String> {
+    let export = JsonExport { ast, table };
+    serde_json::to_string_pretty(&export)
+}
+
+/// One column per variable plus a result column, for spreadsheets.
+pub fn print_csv(table: &TruthTable) {
+    println!("{}", render_csv(table));
+}
+
+fn render_csv(table: &TruthTable) -> String {
+    let mut header = table.variables.clone();
+    header.push("result".to_string());
+    let mut lines = vec![header.join(",")];
+
+    for row in &table.rows {
+        let mut fields: Vec<String> = row.assignment.iter().map(|(_, v)| v.to_string()).collect();
+        fields.push(row.result.to_string());
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n")
+}
+
+/// Renders `table` as a GitHub-style Markdown table.
+pub fn print_markdown(table: &TruthTable) {
+    println!("{}", render_markdown(table));
+}
+
+fn render_markdown(table: &TruthTable) -> String {
+    let mut header = table.variables.clone();
+    header.push("Resultado".to_string());
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("| {} |", header.iter().map(|_| "---").collect::<Vec<_>>().join(" | ")),
+    ];
+
+    for row in &table.rows {
+        let mut fields: Vec<String> = row.assignment.iter().map(|(_, v)| v.to_string()).collect();
+        fields.push(row.result.to_string());
+        lines.push(format!("| {} |", fields.join(" | ")));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AST;
+
+    fn xor_table() -> TruthTable {
+        let ast = AST::Xor(
+            Box::new(AST::Var("a".to_string())),
+            Box::new(AST::Var("b".to_string())),
+        );
+        let vars = vec!["a".to_string(), "b".to_string()];
+        build_truth_table(&ast, &vars, false)
+    }
+
+    #[test]
+    fn build_truth_table_enumerates_rows_in_column_order() {
+        let table = xor_table();
+        assert_eq!(table.variables, vec!["a", "b"]);
+        assert_eq!(table.rows.len(), 4);
+        assert_eq!(
+            table.rows.iter().map(|r| r.assignment.clone()).collect::<Vec<_>>(),
+            vec![
+                vec![("a".to_string(), false), ("b".to_string(), false)],
+                vec![("a".to_string(), true), ("b".to_string(), false)],
+                vec![("a".to_string(), false), ("b".to_string(), true)],
+                vec![("a".to_string(), true), ("b".to_string(), true)],
+            ]
+        );
+        assert_eq!(
+            table.rows.iter().map(|r| r.result).collect::<Vec<_>>(),
+            vec![false, true, true, false]
+        );
+    }
+
+    #[test]
+    fn render_csv_matches_header_and_rows() {
+        let table = xor_table();
+        assert_eq!(
+            render_csv(&table),
+            "a,b,result\nfalse,false,false\ntrue,false,true\nfalse,true,true\ntrue,true,false"
+        );
+    }
+
+    #[test]
+    fn render_markdown_matches_header_and_rows() {
+        let table = xor_table();
+        assert_eq!(
+            render_markdown(&table),
+            "| a | b | Resultado |\n\
+             | --- | --- | --- |\n\
+             | false | false | false |\n\
+             | true | false | true |\n\
+             | false | true | true |\n\
+             | true | true | false |"
+        );
+    }
+
+    #[test]
+    fn render_json_embeds_ast_and_table() {
+        let ast = AST::Xor(
+            Box::new(AST::Var("a".to_string())),
+            Box::new(AST::Var("b".to_string())),
+        );
+        let table = xor_table();
+        let json = render_json(&ast, &table).expect("serializes");
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(parsed["table"]["variables"], serde_json::json!(["a", "b"]));
+        assert!(parsed["ast"]["Xor"].is_array());
+    }
+}
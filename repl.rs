@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use rustyline::completion::Completer;
+use rustyline::highlight::{CmdKind, Highlighter};
+use rustyline::hint::{Hinter, HistoryHinter};
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Context, Editor, Helper, Result as RlResult};
+
+use crate::env::Environment;
+use crate::{generate_truth_table, parse_expr, tokenize};
+
+const HISTORY_FILE: &str = ".cedilha_history";
+
+/// Ties together validation, highlighting and hinting for the REPL prompt.
+/// `rustyline` drives these three traits off a single helper so a partial
+/// line can be judged incomplete, colored, and hinted in one pass.
+struct CedilhaHelper {
+    hinter: HistoryHinter,
+}
+
+impl Completer for CedilhaHelper {
+    type Candidate = String;
+}
+
+impl Hinter for CedilhaHelper {
+    type Hint = String;
+
+    fn hint(&self, line: &str, pos: usize, ctx: &Context<'_>) -> Option<String> {
+        self.hinter.hint(line, pos, ctx)
+    }
+}
+
+impl Highlighter for CedilhaHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let mut out = String::with_capacity(line.len());
+        for chunk in line.split_inclusive(char::is_whitespace) {
+            let word = chunk.trim_end();
+            let trailing = &chunk[word.len()..];
+            match word {
+                "and" | "or" | "not" | "xor" | "nand" | "nor" | "->" | "<->" => {
+                    out.push_str(&format!("\x1b[1;35m{word}\x1b[0m"))
+                }
+                "(" | ")" => out.push_str(&format!("\x1b[1;36m{word}\x1b[0m")),
+                other => out.push_str(other),
+            }
+            out.push_str(trailing);
+        }
+        Cow::Owned(out)
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, _kind: CmdKind) -> bool {
+        true
+    }
+}
+
+impl Validator for CedilhaHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> RlResult<ValidationResult> {
+        let mut depth = 0i32;
+        for word in ctx.input().split_whitespace() {
+            match word {
+                "(" => depth += 1,
+                ")" => depth -= 1,
+                _ => {}
+            }
+        }
+        if depth > 0 {
+            Ok(ValidationResult::Incomplete)
+        } else {
+            Ok(ValidationResult::Valid(None))
+        }
+    }
+}
+
+impl Helper for CedilhaHelper {}
+
+/// Runs Cedilha as an interactive shell: each line is parsed and fed to
+/// `generate_truth_table`, with `rustyline` handling multi-line
+/// continuation for unbalanced parens, keyword highlighting, and a
+/// history file that persists across sessions. `environment` seeds the
+/// shell with whatever `--defs` already loaded, so defs supplied on the
+/// command line are usable from the first prompt.
+pub fn run(mut environment: Environment) -> RlResult<()> {
+    let helper = CedilhaHelper {
+        hinter: HistoryHinter::new(),
+    };
+    let mut rl: Editor<CedilhaHelper, _> = Editor::new()?;
+    rl.set_helper(Some(helper));
+    let _ = rl.load_history(HISTORY_FILE);
+
+    println!("Cedilha REPL — digite uma expressão booleana, 'let nome = expressão' para definir um termo reutilizável (ctrl-d para sair)");
+
+    loop {
+        match rl.readline(">> ") {
+            Ok(line) => {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                rl.add_history_entry(line.as_str())?;
+
+                if line.trim_start().starts_with("let ") {
+                    match environment.define_line(line.trim()) {
+                        Ok(()) => println!("definido."),
+                        Err(err) => eprintln!("Erro ao definir \"{line}\": {err}"),
+                    }
+                    continue;
+                }
+
+                let tokens = tokenize(&line);
+                let mut token_queue = VecDeque::from(tokens);
+                match parse_expr(&mut token_queue).and_then(|ast| environment.expand_expr(&ast)) {
+                    Ok(ast) => {
+                        println!("AST: {:?}", ast);
+                        generate_truth_table(&ast);
+                    }
+                    Err(err) => eprintln!("Erro ao interpretar \"{line}\": {err}"),
+                }
+            }
+            Err(rustyline::error::ReadlineError::Interrupted)
+            | Err(rustyline::error::ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("Erro de leitura: {err}");
+                break;
+            }
+        }
+    }
+
+    let _ = rl.save_history(HISTORY_FILE);
+    Ok(())
+}